@@ -15,19 +15,60 @@ use alloc::vec::Vec;
 
 /// Import items from the SDK. The prelude contains common traits and macros.
 use stylus_sdk::{
-    alloy_primitives::{Address, U256},
+    alloy_primitives::{Address, B256, U256},
     alloy_sol_types::sol,
+    call::Call,
+    crypto::keccak,
+    evm, msg,
     prelude::*,
 };
 
 /// Import OpenZeppelin Ownable functionality
 use openzeppelin_stylus::access::ownable::{self, IOwnable, Ownable};
 
+/// The default admin role, identical to OpenZeppelin's `AccessControl`
+/// convention: it is its own admin and is granted to `initial_owner` by the
+/// constructor.
+pub const DEFAULT_ADMIN_ROLE: B256 = B256::ZERO;
+
+/// Role required to add or remove tracked protocols.
+pub fn protocol_manager_role() -> B256 {
+    keccak("PROTOCOL_MANAGER_ROLE")
+}
+
+/// Upper bound for `protocol_weight` (basis points): 100x a protocol's raw
+/// yield. Keeps `protocol_yield * weight` comfortably clear of `U256`
+/// overflow for any realistic yield figure.
+fn max_protocol_weight_bps() -> U256 {
+    U256::from(1_000_000u64)
+}
+
+/// Verify a Merkle proof using the standard sorted-pair folding scheme:
+/// starting from `leaf`, for each sibling `p`, `hash = keccak256(hash ++ p)`
+/// if `hash <= p` else `keccak256(p ++ hash)`; the fold must land on `root`.
+fn verify_merkle_proof(leaf: B256, proof: &[B256], root: B256) -> bool {
+    let mut computed_hash = leaf;
+    for sibling in proof {
+        computed_hash = if computed_hash <= *sibling {
+            keccak([computed_hash.as_slice(), sibling.as_slice()].concat())
+        } else {
+            keccak([sibling.as_slice(), computed_hash.as_slice()].concat())
+        };
+    }
+    computed_hash == root
+}
+
 /// Error types for the contract
 #[derive(SolidityError, Debug)]
 pub enum Error {
     UnauthorizedAccount(ownable::OwnableUnauthorizedAccount),
     InvalidOwner(ownable::OwnableInvalidOwner),
+    AccessControlUnauthorizedAccount(AccessControlUnauthorizedAccount),
+    AccessControlBadConfirmation(AccessControlBadConfirmation),
+    InvalidProof(InvalidProof),
+    EnforcedPause(EnforcedPause),
+    InvalidFeeBps(InvalidFeeBps),
+    InvalidWeightBps(InvalidWeightBps),
 }
 
 impl From<ownable::Error> for Error {
@@ -44,10 +85,24 @@ sol! {
     event ProtocolAdded(address indexed protocol, address indexed owner);
     event ProtocolRemoved(address indexed protocol, address indexed owner);
     event YieldCalculated(address indexed user, uint256 totalYield, uint256 protocolCount);
+    event ProtocolCallFailed(address indexed protocol, address indexed user);
+    event RoleGranted(bytes32 indexed role, address indexed account, address indexed sender);
+    event RoleRevoked(bytes32 indexed role, address indexed account, address indexed sender);
+    event FeeApplied(address indexed user, uint256 grossYield, uint256 feeTaken, uint256 netYield);
+    event Paused(address account);
+    event Unpaused(address account);
+
+    error AccessControlUnauthorizedAccount(address account, bytes32 neededRole);
+    error AccessControlBadConfirmation();
+    error InvalidProof();
+    error EnforcedPause();
+    error InvalidFeeBps(uint256 feeBps);
+    error InvalidWeightBps(uint256 weightBps);
 }
 
-// Interface for external protocols
-sol! {
+// Interface for external protocols, callable via the Stylus SDK's cross-contract
+// call machinery.
+sol_interface! {
     interface IProtocol {
         function getYield(address user) external view returns (uint256);
         function getName() external view returns (string memory);
@@ -62,6 +117,14 @@ sol_storage! {
         address[] protocols;
         mapping(address => uint256) protocol_index;
         uint256 protocol_count;
+        mapping(bytes32 => mapping(address => bool)) roles;
+        mapping(bytes32 => bytes32) role_admin;
+        bytes32 merkle_root;
+        mapping(address => uint256) protocol_weight;
+        mapping(address => bool) protocol_weight_set;
+        uint256 fee_bps;
+        address fee_recipient;
+        bool paused;
     }
 }
 
@@ -74,77 +137,226 @@ impl YieldAggregator {
         // Initialize Ownable with the initial owner using OpenZeppelin pattern
         self.ownable.constructor(initial_owner)?;
         self.protocol_count.set(U256::ZERO);
+
+        // Preserve existing single-owner ergonomics: the initial owner is
+        // both the default admin and a protocol manager out of the box.
+        self._grant_role(DEFAULT_ADMIN_ROLE, initial_owner);
+        self._grant_role(protocol_manager_role(), initial_owner);
+
+        Ok(())
+    }
+
+    /// Pause protocol management and the external-call yield aggregation
+    /// (owner only). Acts as a kill-switch if a tracked protocol is
+    /// compromised and returning manipulated yield figures.
+    pub fn pause(&mut self) -> Result<(), Error> {
+        self.ownable.only_owner()?;
+        self.paused.set(true);
+        evm::log(Paused {
+            account: msg::sender(),
+        });
         Ok(())
     }
 
-    /// Add a new protocol to track (only owner)
+    /// Lift the pause (owner only)
+    pub fn unpause(&mut self) -> Result<(), Error> {
+        self.ownable.only_owner()?;
+        self.paused.set(false);
+        evm::log(Unpaused {
+            account: msg::sender(),
+        });
+        Ok(())
+    }
+
+    /// Check whether the contract is currently paused
+    pub fn is_paused(&self) -> bool {
+        self.paused.get()
+    }
+
+    /// Add a new protocol to track (requires `PROTOCOL_MANAGER_ROLE`)
     pub fn add_protocol(&mut self, protocol: Address) -> Result<(), Error> {
-        // Check if caller is owner
+        self.when_not_paused()?;
+        self.only_role(protocol_manager_role())?;
+        self._track_protocol(protocol);
+        Ok(())
+    }
+
+    /// Set the Merkle root committing to the set of protocols the manager
+    /// has approved off-chain (owner only)
+    pub fn set_protocol_merkle_root(&mut self, root: B256) -> Result<(), Error> {
         self.ownable.only_owner()?;
+        self.merkle_root.set(root);
+        Ok(())
+    }
 
-        // Check if protocol already exists
-        let current_count = self.protocol_count.get();
-        for i in 0..current_count.to::<u32>() {
-            if let Some(existing_protocol) = self.protocols.get(U256::from(i)) {
-                if existing_protocol == protocol {
-                    // Return success if protocol already exists (idempotent)
-                    return Ok(());
-                }
-            }
+    /// Add a protocol that was approved off-chain, proving membership
+    /// against `merkle_root` instead of requiring `PROTOCOL_MANAGER_ROLE`
+    /// for every single addition.
+    ///
+    /// Uses the standard sorted-pair folding scheme: `leaf = keccak256(protocol)`,
+    /// then for each sibling `p`, `hash = keccak256(hash ++ p)` if `hash <= p`
+    /// else `keccak256(p ++ hash)`.
+    pub fn add_protocol_with_proof(
+        &mut self,
+        protocol: Address,
+        proof: Vec<B256>,
+    ) -> Result<(), Error> {
+        self.when_not_paused()?;
+
+        let leaf = keccak(protocol.as_slice());
+        if !verify_merkle_proof(leaf, &proof, self.merkle_root.get()) {
+            return Err(Error::InvalidProof(InvalidProof {}));
         }
 
-        // Add protocol to the list
-        self.protocols.push(protocol);
-        self.protocol_index.insert(protocol, current_count);
-        self.protocol_count.set(current_count + U256::from(1));
-
+        self._track_protocol(protocol);
         Ok(())
     }
 
-    /// Remove a protocol from tracking (only owner)
+    /// Remove a protocol from tracking (requires `PROTOCOL_MANAGER_ROLE`)
+    ///
+    /// O(1) swap-and-pop: the removed slot is overwritten with the last
+    /// element, `protocol_index` is updated for the moved element, and the
+    /// tail is popped off.
     pub fn remove_protocol(&mut self, protocol: Address) -> Result<(), Error> {
-        // Check if caller is owner
-        self.ownable.only_owner()?;
+        self.when_not_paused()?;
+        self.only_role(protocol_manager_role())?;
+
+        let stored_index = self.protocol_index.get(protocol);
+        if stored_index.is_zero() {
+            // Not tracked; nothing to do (idempotent, mirrors add_protocol).
+            return Ok(());
+        }
 
         let current_count = self.protocol_count.get();
+        let remove_idx = stored_index - U256::from(1);
+        let last_idx = current_count - U256::from(1);
 
-        // Find the protocol in the array
-        let mut found_idx = None;
-        for i in 0..current_count.to::<u32>() {
-            if let Some(existing_protocol) = self.protocols.get(U256::from(i)) {
-                if existing_protocol == protocol {
-                    found_idx = Some(U256::from(i));
-                    break;
+        if remove_idx != last_idx {
+            if let Some(last_protocol) = self.protocols.get(last_idx) {
+                if let Some(mut slot) = self.protocols.setter(remove_idx) {
+                    slot.set(last_protocol);
                 }
+                self.protocol_index
+                    .insert(last_protocol, remove_idx + U256::from(1));
             }
         }
 
-        if found_idx.is_some() {
-            // For simplicity, just remove the last element and rebuild if needed
-            // This is not optimal but works for our demo
-            self.protocols.pop();
-            self.protocol_index.insert(protocol, U256::ZERO);
-            self.protocol_count.set(current_count - U256::from(1));
-        }
+        self.protocols.pop();
+        self.protocol_index.insert(protocol, U256::ZERO);
+        self.protocol_count.set(current_count - U256::from(1));
 
         Ok(())
     }
 
-    /// Get total yield for a user across all protocols
-    pub fn get_total_yield(&self, user: Address) -> U256 {
+    /// Get total yield for a user across all protocols, weighted by each
+    /// protocol's `protocol_weight` (basis points, default 10000 = 1x).
+    ///
+    /// Calls `IProtocol::getYield` on every tracked protocol and sums the
+    /// weighted results. A protocol that reverts or otherwise fails to
+    /// answer is skipped rather than aborting the whole aggregation; a
+    /// `ProtocolCallFailed` event is emitted for it so callers can notice.
+    /// Reverts with `EnforcedPause` while the contract is paused.
+    pub fn get_total_yield(&mut self, user: Address) -> Result<U256, Error> {
+        self.when_not_paused()?;
+
         let mut total_yield = U256::ZERO;
+        let mut responded = U256::ZERO;
         let protocol_count = self.protocol_count.get();
 
         for i in 0..protocol_count.to::<u32>() {
             if let Some(protocol_address) = self.protocols.get(U256::from(i)) {
-                // In a real implementation, this would call the protocol contract
-                // For now, we'll return mock data based on protocol address
-                let protocol_yield = self.get_mock_yield(protocol_address, user);
-                total_yield += protocol_yield;
+                let protocol = IProtocol::new(protocol_address);
+                match protocol.get_yield(Call::new_in(self), user) {
+                    Ok(protocol_yield) => {
+                        // Only protocols with an explicit entry use that weight
+                        // (including an explicit 0); everything else defaults
+                        // to 10000 (1x). `protocol_weight` is capped at
+                        // `max_protocol_weight_bps()` by `set_protocol_weight`,
+                        // but `checked_mul` is kept as a second line of
+                        // defense: an overflowing weighted yield is skipped
+                        // rather than reverting the whole aggregation.
+                        let weight = if self.protocol_weight_set.get(protocol_address) {
+                            self.protocol_weight.get(protocol_address)
+                        } else {
+                            U256::from(10000)
+                        };
+                        if let Some(weighted_yield) = protocol_yield.checked_mul(weight) {
+                            total_yield += weighted_yield / U256::from(10000);
+                        }
+                        responded += U256::from(1);
+                    }
+                    Err(_) => {
+                        evm::log(ProtocolCallFailed {
+                            protocol: protocol_address,
+                            user,
+                        });
+                    }
+                }
             }
         }
 
-        total_yield
+        evm::log(YieldCalculated {
+            user,
+            totalYield: total_yield,
+            protocolCount: responded,
+        });
+
+        Ok(total_yield)
+    }
+
+    /// Get the weighted total yield for a user minus the protocol fee
+    /// (`fee_bps`), emitting `FeeApplied` with the gross/fee/net breakdown
+    /// so indexers can track accrued fees.
+    ///
+    /// `YieldAggregator` never holds the underlying yield tokens itself —
+    /// it only aggregates figures reported by other protocols — so the fee
+    /// is accounting-only here: `fee_recipient` records who it accrues to
+    /// off-chain, this method does not move any tokens.
+    pub fn get_net_yield(&mut self, user: Address) -> Result<U256, Error> {
+        let gross_yield = self.get_total_yield(user)?;
+        let fee = gross_yield * self.fee_bps.get() / U256::from(10000);
+        let net_yield = gross_yield - fee;
+
+        evm::log(FeeApplied {
+            user,
+            grossYield: gross_yield,
+            feeTaken: fee,
+            netYield: net_yield,
+        });
+
+        Ok(net_yield)
+    }
+
+    /// Set the weight (basis points, default 10000 = 1x) applied to a
+    /// protocol's yield before it's summed (requires `PROTOCOL_MANAGER_ROLE`).
+    /// A weight of `0` is a valid choice and excludes the protocol's yield
+    /// from the total; capped at `max_protocol_weight_bps()` (100x).
+    pub fn set_protocol_weight(
+        &mut self,
+        protocol: Address,
+        weight_bps: U256,
+    ) -> Result<(), Error> {
+        self.only_role(protocol_manager_role())?;
+        if weight_bps > max_protocol_weight_bps() {
+            return Err(Error::InvalidWeightBps(InvalidWeightBps {
+                weightBps: weight_bps,
+            }));
+        }
+        self.protocol_weight.insert(protocol, weight_bps);
+        self.protocol_weight_set.insert(protocol, true);
+        Ok(())
+    }
+
+    /// Set the management fee (basis points, capped at 10000 = 100%) and the
+    /// address it accrues to (requires `PROTOCOL_MANAGER_ROLE`)
+    pub fn set_fee(&mut self, fee_bps: U256, fee_recipient: Address) -> Result<(), Error> {
+        self.only_role(protocol_manager_role())?;
+        if fee_bps > U256::from(10000) {
+            return Err(Error::InvalidFeeBps(InvalidFeeBps { feeBps: fee_bps }));
+        }
+        self.fee_bps.set(fee_bps);
+        self.fee_recipient.set(fee_recipient);
+        Ok(())
     }
 
     /// Get list of all tracked protocols
@@ -166,35 +378,121 @@ impl YieldAggregator {
         self.protocol_count.get()
     }
 
-    /// Check if a protocol is tracked
+    /// Get the current management fee, in basis points
+    pub fn get_fee_bps(&self) -> U256 {
+        self.fee_bps.get()
+    }
+
+    /// Get the address the management fee accrues to (accounting-only; see
+    /// `get_net_yield`)
+    pub fn get_fee_recipient(&self) -> Address {
+        self.fee_recipient.get()
+    }
+
+    /// Check if a protocol is tracked (O(1) via `protocol_index`)
     pub fn is_protocol_tracked(&self, protocol: Address) -> bool {
-        let protocol_count = self.protocol_count.get();
-        for i in 0..protocol_count.to::<u32>() {
-            if let Some(existing_protocol) = self.protocols.get(U256::from(i)) {
-                if existing_protocol == protocol {
-                    return true;
-                }
-            }
+        !self.protocol_index.get(protocol).is_zero()
+    }
+
+    /// Check whether `account` holds `role`
+    pub fn has_role(&self, role: B256, account: Address) -> bool {
+        self.roles.getter(role).get(account)
+    }
+
+    /// Get the admin role that governs granting/revoking `role`
+    pub fn get_role_admin(&self, role: B256) -> B256 {
+        self.role_admin.get(role)
+    }
+
+    /// Grant `role` to `account` (requires the role's admin role)
+    pub fn grant_role(&mut self, role: B256, account: Address) -> Result<(), Error> {
+        let admin_role = self.get_role_admin(role);
+        self.only_role(admin_role)?;
+        self._grant_role(role, account);
+        Ok(())
+    }
+
+    /// Revoke `role` from `account` (requires the role's admin role)
+    pub fn revoke_role(&mut self, role: B256, account: Address) -> Result<(), Error> {
+        let admin_role = self.get_role_admin(role);
+        self.only_role(admin_role)?;
+        self._revoke_role(role, account);
+        Ok(())
+    }
+
+    /// Give up `role` for the calling account. `confirmation` must equal the
+    /// caller's address, mirroring OpenZeppelin's guard against accidentally
+    /// renouncing another account's role.
+    pub fn renounce_role(&mut self, role: B256, confirmation: Address) -> Result<(), Error> {
+        if confirmation != msg::sender() {
+            return Err(Error::AccessControlBadConfirmation(
+                AccessControlBadConfirmation {},
+            ));
         }
-        false
-    }
-
-    /// Mock yield calculation (replace with actual protocol calls in production)
-    fn get_mock_yield(&self, protocol: Address, _user: Address) -> U256 {
-        // Mock yield based on protocol address for testing
-        let protocol_bytes = protocol.as_slice();
-        let seed = u32::from_be_bytes([
-            protocol_bytes[16],
-            protocol_bytes[17], 
-            protocol_bytes[18],
-            protocol_bytes[19],
-        ]);
-        
-        // Generate different yields for different protocols
-        match seed % 3 {
-            0 => U256::from(5000000000000000u64), // 0.005 ETH
-            1 => U256::from(8000000000000000u64), // 0.008 ETH
-            _ => U256::from(3000000000000000u64), // 0.003 ETH
+        self._revoke_role(role, confirmation);
+        Ok(())
+    }
+
+    /// Push `protocol` onto the tracked list if it isn't already tracked.
+    ///
+    /// `protocol_index` stores `index + 1` so that `0` unambiguously means
+    /// "not tracked", giving O(1) existence checks instead of an array scan.
+    fn _track_protocol(&mut self, protocol: Address) {
+        if self.is_protocol_tracked(protocol) {
+            return;
+        }
+
+        let current_count = self.protocol_count.get();
+        self.protocols.push(protocol);
+        self.protocol_index
+            .insert(protocol, current_count + U256::from(1));
+        self.protocol_count.set(current_count + U256::from(1));
+    }
+
+    /// Require that the contract is not paused
+    fn when_not_paused(&self) -> Result<(), Error> {
+        if self.paused.get() {
+            return Err(Error::EnforcedPause(EnforcedPause {}));
+        }
+        Ok(())
+    }
+
+    /// Require that the caller holds `role`
+    fn only_role(&self, role: B256) -> Result<(), Error> {
+        let account = msg::sender();
+        if !self.has_role(role, account) {
+            return Err(Error::AccessControlUnauthorizedAccount(
+                AccessControlUnauthorizedAccount {
+                    account,
+                    neededRole: role,
+                },
+            ));
+        }
+        Ok(())
+    }
+
+    /// Grant `role` to `account`, emitting `RoleGranted` if it was not
+    /// already held
+    fn _grant_role(&mut self, role: B256, account: Address) {
+        if !self.has_role(role, account) {
+            self.roles.setter(role).insert(account, true);
+            evm::log(RoleGranted {
+                role,
+                account,
+                sender: msg::sender(),
+            });
+        }
+    }
+
+    /// Revoke `role` from `account`, emitting `RoleRevoked` if it was held
+    fn _revoke_role(&mut self, role: B256, account: Address) {
+        if self.has_role(role, account) {
+            self.roles.setter(role).insert(account, false);
+            evm::log(RoleRevoked {
+                role,
+                account,
+                sender: msg::sender(),
+            });
         }
     }
 }
@@ -216,3 +514,145 @@ impl IOwnable for YieldAggregator {
         Ok(self.ownable.renounce_ownership()?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaf_for(byte: u8) -> B256 {
+        keccak(Address::repeat_byte(byte).as_slice())
+    }
+
+    fn hash_pair(a: B256, b: B256) -> B256 {
+        if a <= b {
+            keccak([a.as_slice(), b.as_slice()].concat())
+        } else {
+            keccak([b.as_slice(), a.as_slice()].concat())
+        }
+    }
+
+    #[test]
+    fn verify_merkle_proof_accepts_a_valid_two_leaf_proof() {
+        let leaf_a = leaf_for(0xAA);
+        let leaf_b = leaf_for(0xBB);
+        let root = hash_pair(leaf_a, leaf_b);
+
+        // Sorted-pair folding must be order-independent of which leaf is proven.
+        assert!(verify_merkle_proof(leaf_a, &[leaf_b], root));
+        assert!(verify_merkle_proof(leaf_b, &[leaf_a], root));
+    }
+
+    #[test]
+    fn verify_merkle_proof_rejects_the_wrong_root() {
+        let leaf_a = leaf_for(0xAA);
+        let leaf_b = leaf_for(0xBB);
+        let wrong_root = leaf_for(0xCC);
+
+        assert!(!verify_merkle_proof(leaf_a, &[leaf_b], wrong_root));
+    }
+
+    #[test]
+    fn verify_merkle_proof_rejects_a_proof_missing_a_level() {
+        let leaf_a = leaf_for(0xAA);
+        let leaf_b = leaf_for(0xBB);
+        let leaf_c = leaf_for(0xCC);
+        let root = hash_pair(hash_pair(leaf_a, leaf_b), leaf_c);
+
+        // Only the first level of the three-leaf tree is proven.
+        assert!(!verify_merkle_proof(leaf_a, &[leaf_b], root));
+    }
+
+    #[test]
+    fn remove_protocol_swap_and_pop_keeps_index_consistent() {
+        use stylus_sdk::testing::*;
+
+        let vm = TestVM::default();
+        let mut contract = YieldAggregator::from(&vm);
+
+        let owner = Address::repeat_byte(0x01);
+        let protocol_a = Address::repeat_byte(0xA1);
+        let protocol_b = Address::repeat_byte(0xA2);
+        let protocol_c = Address::repeat_byte(0xA3);
+
+        vm.set_sender(owner);
+        contract.constructor(owner).unwrap();
+        contract.add_protocol(protocol_a).unwrap();
+        contract.add_protocol(protocol_b).unwrap();
+        contract.add_protocol(protocol_c).unwrap();
+
+        // Remove the middle element; protocol_c (the last element) should be
+        // swapped into its slot and protocol_index updated to match.
+        contract.remove_protocol(protocol_b).unwrap();
+
+        assert!(!contract.is_protocol_tracked(protocol_b));
+        assert!(contract.is_protocol_tracked(protocol_a));
+        assert!(contract.is_protocol_tracked(protocol_c));
+        assert_eq!(contract.get_protocol_count(), U256::from(2));
+
+        let protocols = contract.get_protocols();
+        assert_eq!(protocols.len(), 2);
+        assert!(protocols.contains(&protocol_a));
+        assert!(protocols.contains(&protocol_c));
+
+        // Removing an already-absent protocol is a no-op, not an error.
+        contract.remove_protocol(protocol_b).unwrap();
+        assert_eq!(contract.get_protocol_count(), U256::from(2));
+    }
+
+    #[test]
+    fn add_protocol_requires_protocol_manager_role() {
+        use stylus_sdk::testing::*;
+
+        let vm = TestVM::default();
+        let mut contract = YieldAggregator::from(&vm);
+
+        let owner = Address::repeat_byte(0x01);
+        let stranger = Address::repeat_byte(0x02);
+        let protocol = Address::repeat_byte(0x03);
+
+        vm.set_sender(owner);
+        contract.constructor(owner).unwrap();
+
+        vm.set_sender(stranger);
+        let err = contract.add_protocol(protocol).unwrap_err();
+        assert!(matches!(err, Error::AccessControlUnauthorizedAccount(_)));
+        assert!(!contract.is_protocol_tracked(protocol));
+
+        vm.set_sender(owner);
+        contract.add_protocol(protocol).unwrap();
+        assert!(contract.is_protocol_tracked(protocol));
+    }
+
+    #[test]
+    fn grant_and_revoke_role() {
+        use stylus_sdk::testing::*;
+
+        let vm = TestVM::default();
+        let mut contract = YieldAggregator::from(&vm);
+
+        let owner = Address::repeat_byte(0x01);
+        let manager = Address::repeat_byte(0x04);
+
+        vm.set_sender(owner);
+        contract.constructor(owner).unwrap();
+
+        assert!(!contract.has_role(protocol_manager_role(), manager));
+
+        contract
+            .grant_role(protocol_manager_role(), manager)
+            .unwrap();
+        assert!(contract.has_role(protocol_manager_role(), manager));
+
+        contract
+            .revoke_role(protocol_manager_role(), manager)
+            .unwrap();
+        assert!(!contract.has_role(protocol_manager_role(), manager));
+
+        // Only DEFAULT_ADMIN_ROLE holders may grant/revoke.
+        vm.set_sender(manager);
+        let err = contract
+            .grant_role(protocol_manager_role(), manager)
+            .unwrap_err();
+        assert!(matches!(err, Error::AccessControlUnauthorizedAccount(_)));
+    }
+}